@@ -224,6 +224,158 @@ pub mod parachain {
 		pub block: BlockData,
 	}
 
+	/// The hash algorithm a parachain uses to commit to its head-data and egress queue roots.
+	#[derive(Clone, Copy, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug, Serialize))]
+	pub enum HashAlgo {
+		/// Blake2-256, as used natively by the relay chain.
+		Blake2_256,
+		/// Keccak256, as used by EVM-compatible parachains.
+		Keccak256,
+	}
+
+	impl Default for HashAlgo {
+		fn default() -> Self {
+			HashAlgo::Blake2_256
+		}
+	}
+
+	impl Slicable for HashAlgo {
+		fn decode<I: Input>(input: &mut I) -> Option<Self> {
+			match input.read_byte()? {
+				0 => Some(HashAlgo::Blake2_256),
+				1 => Some(HashAlgo::Keccak256),
+				_ => None,
+			}
+		}
+
+		fn encode(&self) -> Vec<u8> {
+			match *self {
+				HashAlgo::Blake2_256 => vec![0],
+				HashAlgo::Keccak256 => vec![1],
+			}
+		}
+
+		fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+			f(&self.encode().as_slice())
+		}
+	}
+
+	impl HashAlgo {
+		/// Hash a `Slicable` value's encoding under this algorithm.
+		#[cfg(feature = "std")]
+		pub fn hash_of<T: Slicable>(&self, x: &T) -> Hash {
+			match *self {
+				HashAlgo::Blake2_256 => {
+					use runtime_primitives::traits::Hashing;
+					BlakeTwo256::hash_of(x)
+				}
+				HashAlgo::Keccak256 => Hash::from(keccak_256(&x.encode())),
+			}
+		}
+	}
+
+	// `runtime_primitives` only vendors a `BlakeTwo256` `Hashing` impl, and this crate
+	// does not depend on a Keccak crate, so Keccak-256 (the original Keccak padding, as
+	// used by Ethereum, not the later NIST SHA3 padding) is implemented directly below
+	// rather than pulling in an undeclared dependency.
+
+	#[cfg(feature = "std")]
+	const KECCAK_ROUNDS: usize = 24;
+	#[cfg(feature = "std")]
+	const KECCAK_RATE_BYTES: usize = 136;
+
+	#[cfg(feature = "std")]
+	const KECCAK_RC: [u64; KECCAK_ROUNDS] = [
+		0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+		0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+		0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+		0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+		0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+		0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+	];
+
+	#[cfg(feature = "std")]
+	const KECCAK_RHO: [u32; 25] = [
+		0, 1, 62, 28, 27,
+		36, 44, 6, 55, 20,
+		3, 10, 43, 25, 39,
+		41, 45, 15, 21, 8,
+		18, 2, 61, 56, 14,
+	];
+
+	#[cfg(feature = "std")]
+	const KECCAK_PI: [usize; 25] = [
+		0, 10, 20, 5, 15,
+		16, 1, 11, 21, 6,
+		7, 17, 2, 12, 22,
+		23, 8, 18, 3, 13,
+		14, 24, 9, 19, 4,
+	];
+
+	#[cfg(feature = "std")]
+	fn keccak_f(state: &mut [u64; 25]) {
+		for round in 0..KECCAK_ROUNDS {
+			let mut c = [0u64; 5];
+			for x in 0..5 {
+				c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+			}
+			let mut d = [0u64; 5];
+			for x in 0..5 {
+				d[x] = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+			}
+			for x in 0..5 {
+				for y in 0..5 {
+					state[x + 5 * y] ^= d[x];
+				}
+			}
+
+			let mut b = [0u64; 25];
+			for i in 0..25 {
+				b[KECCAK_PI[i]] = state[i].rotate_left(KECCAK_RHO[i]);
+			}
+
+			for y in 0..5 {
+				for x in 0..5 {
+					state[x + 5 * y] = b[x + 5 * y]
+						^ ((!b[(x + 1) % 5 + 5 * y]) & b[(x + 2) % 5 + 5 * y]);
+				}
+			}
+
+			state[0] ^= KECCAK_RC[round];
+		}
+	}
+
+	/// The Keccak-256 digest of `data`, computed locally via the Keccak-f[1600]
+	/// permutation and sponge construction (no external hashing crate required).
+	#[cfg(feature = "std")]
+	fn keccak_256(data: &[u8]) -> [u8; 32] {
+		let mut state = [0u64; 25];
+
+		let mut input = data.to_vec();
+		input.push(0x01);
+		while input.len() % KECCAK_RATE_BYTES != 0 {
+			input.push(0);
+		}
+		let last = input.len() - 1;
+		input[last] |= 0x80;
+
+		for chunk in input.chunks(KECCAK_RATE_BYTES) {
+			for (i, word) in chunk.chunks(8).enumerate() {
+				let mut buf = [0u8; 8];
+				buf[..word.len()].copy_from_slice(word);
+				state[i] ^= u64::from_le_bytes(buf);
+			}
+			keccak_f(&mut state);
+		}
+
+		let mut out = [0u8; 32];
+		for i in 0..4 {
+			out[i * 8..i * 8 + 8].copy_from_slice(&state[i].to_le_bytes());
+		}
+		out
+	}
+
 	/// Candidate receipt type.
 	#[derive(PartialEq, Eq, Clone)]
 	#[cfg_attr(feature = "std", derive(Debug, Serialize))]
@@ -238,10 +390,13 @@ pub mod parachain {
 		pub head_data: HeadData,
 		/// Balance uploads to the relay chain.
 		pub balance_uploads: Vec<(super::AccountId, u64)>,
-		/// Egress queue roots.
+		/// Egress queue roots, as committed to by the parachain under its chosen `hash_algo`.
 		pub egress_queue_roots: Vec<(Id, Hash)>,
 		/// Fees paid from the chain to the relay chain validators
 		pub fees: u64,
+		/// The hash algorithm the collator claims this parachain commits with. Self-reported,
+		/// so must be checked against `ValidationCodeMeta::hash_algo` before being trusted.
+		pub hash_algo: HashAlgo,
 	}
 
 	impl Slicable for CandidateReceipt {
@@ -254,6 +409,7 @@ pub mod parachain {
 			self.balance_uploads.using_encoded(|s| v.extend(s));
 			self.egress_queue_roots.using_encoded(|s| v.extend(s));
 			self.fees.using_encoded(|s| v.extend(s));
+			self.hash_algo.using_encoded(|s| v.extend(s));
 
 			v
 		}
@@ -266,16 +422,21 @@ pub mod parachain {
 				balance_uploads: Slicable::decode(input)?,
 				egress_queue_roots: Slicable::decode(input)?,
 				fees: Slicable::decode(input)?,
+				hash_algo: Slicable::decode(input)?,
 			})
 		}
 	}
 
 	impl CandidateReceipt {
-		/// Get the blake2_256 hash
+		/// Get the hash of this receipt, computed under `registered.hash_algo`. Returns
+		/// `None` if `self.hash_algo` does not match the registered one.
 		#[cfg(feature = "std")]
-		pub fn hash(&self) -> Hash {
-			use runtime_primitives::traits::Hashing;
-			BlakeTwo256::hash_of(self)
+		pub fn hash(&self, registered: &ValidationCodeMeta) -> Option<Hash> {
+			if self.hash_algo != registered.hash_algo {
+				return None;
+			}
+
+			Some(self.hash_algo.hash_of(self))
 		}
 	}
 
@@ -324,10 +485,24 @@ pub mod parachain {
 	pub struct HeadData(#[cfg_attr(feature = "std", serde(with="bytes"))] pub Vec<u8>);
 
 	/// Parachain validation code.
-	#[derive(PartialEq, Eq)]
+	#[derive(PartialEq, Eq, Clone)]
 	#[cfg_attr(feature = "std", derive(Serialize, Debug))]
 	pub struct ValidationCode(#[cfg_attr(feature = "std", serde(with="bytes"))] pub Vec<u8>);
 
+	/// On-chain metadata the relay chain keeps about a parachain's registered
+	/// validation code, keyed by that parachain's `Id`. `hash_algo` is the canonical
+	/// digest function the parachain is registered to commit its head-data and
+	/// egress queue roots with -- it is sourced here, not from whatever algorithm a
+	/// candidate happens to claim for itself.
+	#[derive(PartialEq, Eq, Clone)]
+	#[cfg_attr(feature = "std", derive(Serialize, Debug))]
+	pub struct ValidationCodeMeta {
+		/// The validation (WASM) code itself.
+		pub code: ValidationCode,
+		/// The hash algorithm this parachain is registered to commit with.
+		pub hash_algo: HashAlgo,
+	}
+
 	/// Activitiy bit field
 	#[derive(PartialEq, Eq, Clone, Default)]
 	#[cfg_attr(feature = "std", derive(Serialize, Debug))]
@@ -410,4 +585,421 @@ pub mod parachain {
 			}
 		}
 	}
+
+	/// A statement together with its signature and the session key of whoever signed it.
+	#[derive(Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct SignedStatement {
+		/// The statement which was signed.
+		pub statement: Statement,
+		/// The session key of the signer.
+		pub signer: SessionKey,
+		/// The signature over the statement, made with the signer's session key.
+		pub signature: Signature,
+		/// The relay-chain height at which this statement was signed.
+		pub height: BlockNumber,
+	}
+
+	impl Slicable for SignedStatement {
+		fn decode<I: Input>(input: &mut I) -> Option<Self> {
+			Some(SignedStatement {
+				statement: Slicable::decode(input)?,
+				signer: Slicable::decode(input)?,
+				signature: Slicable::decode(input)?,
+				height: Slicable::decode(input)?,
+			})
+		}
+
+		fn encode(&self) -> Vec<u8> {
+			let mut v = Vec::new();
+
+			v.extend(self.statement.encode());
+			self.signer.using_encoded(|s| v.extend(s));
+			self.signature.using_encoded(|s| v.extend(s));
+			self.height.using_encoded(|s| v.extend(s));
+
+			v
+		}
+
+		fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+			f(&self.encode().as_slice())
+		}
+	}
+
+	impl SignedStatement {
+		/// Check that the signature on this statement is valid for the claimed signer.
+		/// Rejects an `Unsigned` signature rather than treating it as vacuously valid.
+		#[cfg(feature = "std")]
+		pub fn verify(&self) -> bool {
+			use runtime_primitives::{MaybeUnsigned, traits::Verify};
+
+			let sig = match self.signature {
+				MaybeUnsigned::Signed(ref sig) => sig,
+				_ => return false,
+			};
+
+			let mut payload = self.statement.encode();
+			payload.extend(self.height.encode());
+			sig.verify(payload.as_slice(), &self.signer)
+		}
+	}
+
+	#[derive(Clone, Copy, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	#[repr(u8)]
+	enum MisbehaviorKindTag {
+		ValidityDoubleVote = 1,
+		DoubleCandidate = 2,
+	}
+
+	/// Different kinds of misbehavior a validator's session key can be reported for.
+	#[derive(Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub enum MisbehaviorKind {
+		/// The signer voted `Valid` and `Invalid` for the same candidate hash.
+		ValidityDoubleVote(SignedStatement, SignedStatement),
+		/// The signer proposed two distinct candidates for the same parachain at the
+		/// same height.
+		DoubleCandidate(SignedStatement, SignedStatement),
+	}
+
+	impl Slicable for MisbehaviorKind {
+		fn encode(&self) -> Vec<u8> {
+			let mut v = Vec::new();
+			match *self {
+				MisbehaviorKind::ValidityDoubleVote(ref first, ref second) => {
+					v.push(MisbehaviorKindTag::ValidityDoubleVote as u8);
+					v.extend(first.encode());
+					v.extend(second.encode());
+				}
+				MisbehaviorKind::DoubleCandidate(ref first, ref second) => {
+					v.push(MisbehaviorKindTag::DoubleCandidate as u8);
+					v.extend(first.encode());
+					v.extend(second.encode());
+				}
+			}
+
+			v
+		}
+
+		fn decode<I: Input>(value: &mut I) -> Option<Self> {
+			match value.read_byte() {
+				Some(x) if x == MisbehaviorKindTag::ValidityDoubleVote as u8 => {
+					Some(MisbehaviorKind::ValidityDoubleVote(
+						Slicable::decode(value)?,
+						Slicable::decode(value)?,
+					))
+				}
+				Some(x) if x == MisbehaviorKindTag::DoubleCandidate as u8 => {
+					Some(MisbehaviorKind::DoubleCandidate(
+						Slicable::decode(value)?,
+						Slicable::decode(value)?,
+					))
+				}
+				_ => None,
+			}
+		}
+
+		fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+			f(&self.encode().as_slice())
+		}
+	}
+
+	impl MisbehaviorKind {
+		/// The two statements carried by this kind of misbehavior, regardless of variant.
+		fn statements(&self) -> (&SignedStatement, &SignedStatement) {
+			match *self {
+				MisbehaviorKind::ValidityDoubleVote(ref first, ref second) => (first, second),
+				MisbehaviorKind::DoubleCandidate(ref first, ref second) => (first, second),
+			}
+		}
+
+		/// Whether the two statements are genuinely, structurally contradictory -- i.e.
+		/// they could not both have been produced honestly for the same slot. This does
+		/// not check signatures; see `MisbehaviorReport::check`.
+		fn is_contradictory(&self) -> bool {
+			match *self {
+				MisbehaviorKind::ValidityDoubleVote(ref first, ref second) => {
+					match (&first.statement, &second.statement) {
+						(&Statement::Valid(ref a), &Statement::Invalid(ref b)) => a == b,
+						(&Statement::Invalid(ref a), &Statement::Valid(ref b)) => a == b,
+						_ => false,
+					}
+				}
+				MisbehaviorKind::DoubleCandidate(ref first, ref second) => {
+					first.height == second.height &&
+						match (&first.statement, &second.statement) {
+							(&Statement::Candidate(ref a), &Statement::Candidate(ref b)) =>
+								a.parachain_index == b.parachain_index && a != b,
+							_ => false,
+						}
+				}
+			}
+		}
+	}
+
+	/// A report that a validator's session key has signed contradictory statements.
+	#[derive(Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct MisbehaviorReport {
+		/// The session key of the validator being reported.
+		pub target: SessionKey,
+		/// The kind of misbehavior, carrying the offending statements.
+		pub kind: MisbehaviorKind,
+	}
+
+	impl Slicable for MisbehaviorReport {
+		fn decode<I: Input>(input: &mut I) -> Option<Self> {
+			Some(MisbehaviorReport {
+				target: Slicable::decode(input)?,
+				kind: Slicable::decode(input)?,
+			})
+		}
+
+		fn encode(&self) -> Vec<u8> {
+			let mut v = Vec::new();
+
+			self.target.using_encoded(|s| v.extend(s));
+			v.extend(self.kind.encode());
+
+			v
+		}
+
+		fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+			f(&self.encode().as_slice())
+		}
+	}
+
+	impl MisbehaviorReport {
+		/// Check that this report is genuine: both statements must be well-signed by `target`
+		/// and must genuinely contradict each other.
+		#[cfg(feature = "std")]
+		pub fn check(&self) -> bool {
+			let (first, second) = self.kind.statements();
+
+			first.signer == self.target && second.signer == self.target &&
+				first.verify() && second.verify() &&
+				self.kind.is_contradictory()
+		}
+	}
+
+	#[cfg(all(test, feature = "std"))]
+	mod tests {
+		use super::*;
+
+		fn session_key(byte: u8) -> SessionKey {
+			let bytes = [byte; 32];
+			Slicable::decode(&mut &bytes[..]).expect("32 bytes decode to a session key")
+		}
+
+		fn zero_signature() -> Signature {
+			let bytes = [0u8; 128];
+			Slicable::decode(&mut &bytes[..]).expect("zero-padded bytes decode to a signature")
+		}
+
+		fn unsigned() -> Signature {
+			runtime_primitives::MaybeUnsigned::Unsigned
+		}
+
+		fn signed(statement: Statement, signer: SessionKey, height: BlockNumber) -> SignedStatement {
+			SignedStatement { statement, signer, signature: zero_signature(), height }
+		}
+
+		fn candidate(parachain_index: u32, head: &[u8]) -> CandidateReceipt {
+			CandidateReceipt {
+				parachain_index: parachain_index.into(),
+				collator: Default::default(),
+				head_data: HeadData(head.to_vec()),
+				balance_uploads: Vec::new(),
+				egress_queue_roots: Vec::new(),
+				fees: 0,
+				hash_algo: HashAlgo::Blake2_256,
+			}
+		}
+
+		#[test]
+		fn validity_double_vote_on_same_candidate_is_contradictory() {
+			let hash = Hash::from([1u8; 32]);
+			let signer = session_key(1);
+			let kind = MisbehaviorKind::ValidityDoubleVote(
+				signed(Statement::Valid(hash), signer.clone(), 1),
+				signed(Statement::Invalid(hash), signer, 1),
+			);
+
+			assert!(kind.is_contradictory());
+		}
+
+		#[test]
+		fn two_valid_votes_for_the_same_candidate_are_not_contradictory() {
+			let hash = Hash::from([1u8; 32]);
+			let signer = session_key(1);
+			let kind = MisbehaviorKind::ValidityDoubleVote(
+				signed(Statement::Valid(hash), signer.clone(), 1),
+				signed(Statement::Valid(hash), signer, 1),
+			);
+
+			assert!(!kind.is_contradictory());
+		}
+
+		#[test]
+		fn distinct_candidates_at_different_heights_are_not_a_double_candidate() {
+			let signer = session_key(1);
+			let kind = MisbehaviorKind::DoubleCandidate(
+				signed(Statement::Candidate(candidate(1, b"a")), signer.clone(), 1),
+				signed(Statement::Candidate(candidate(1, b"b")), signer, 2),
+			);
+
+			assert!(!kind.is_contradictory());
+		}
+
+		#[test]
+		fn distinct_candidates_at_the_same_height_are_a_double_candidate() {
+			let signer = session_key(1);
+			let kind = MisbehaviorKind::DoubleCandidate(
+				signed(Statement::Candidate(candidate(1, b"a")), signer.clone(), 1),
+				signed(Statement::Candidate(candidate(1, b"b")), signer, 1),
+			);
+
+			assert!(kind.is_contradictory());
+		}
+
+		#[test]
+		fn report_against_the_wrong_target_is_rejected() {
+			let hash = Hash::from([1u8; 32]);
+			let signer = session_key(1);
+			let report = MisbehaviorReport {
+				target: session_key(2),
+				kind: MisbehaviorKind::ValidityDoubleVote(
+					signed(Statement::Valid(hash), signer.clone(), 1),
+					signed(Statement::Invalid(hash), signer, 1),
+				),
+			};
+
+			// `target` never signed either statement, so even though the pair is
+			// genuinely contradictory, the report must not be accepted.
+			assert!(!report.check());
+		}
+
+		#[test]
+		fn unsigned_statement_does_not_verify() {
+			let signer = session_key(1);
+			let statement = SignedStatement {
+				statement: Statement::Valid(Hash::from([1u8; 32])),
+				signer,
+				signature: unsigned(),
+				height: 1,
+			};
+
+			assert!(!statement.verify());
+		}
+
+		#[test]
+		fn forged_report_with_unsigned_statements_is_rejected() {
+			// Same signer, same target and genuinely contradictory statements -- but neither
+			// statement actually carries a signature, so this must not be accepted as proof
+			// that `target` misbehaved.
+			let hash = Hash::from([1u8; 32]);
+			let signer = session_key(1);
+			let report = MisbehaviorReport {
+				target: signer.clone(),
+				kind: MisbehaviorKind::ValidityDoubleVote(
+					SignedStatement {
+						statement: Statement::Valid(hash),
+						signer: signer.clone(),
+						signature: unsigned(),
+						height: 1,
+					},
+					SignedStatement {
+						statement: Statement::Invalid(hash),
+						signer,
+						signature: unsigned(),
+						height: 1,
+					},
+				),
+			};
+
+			assert!(!report.check());
+		}
+
+		#[test]
+		fn genuine_double_vote_report_is_accepted() {
+			let pair = substrate_primitives::ed25519::Pair::from_seed(&[1u8; 32]);
+			let public = pair.public().0;
+			let signer: SessionKey = Slicable::decode(&mut &public[..])
+				.expect("ed25519 public key decodes to a session key");
+
+			let sign_at = |statement: Statement, height: BlockNumber| -> SignedStatement {
+				let mut payload = statement.encode();
+				payload.extend(height.encode());
+				let signature = runtime_primitives::MaybeUnsigned::Signed(pair.sign(&payload).into());
+				SignedStatement { statement, signer: signer.clone(), signature, height }
+			};
+
+			let hash = Hash::from([1u8; 32]);
+			let report = MisbehaviorReport {
+				target: signer,
+				kind: MisbehaviorKind::ValidityDoubleVote(
+					sign_at(Statement::Valid(hash), 1),
+					sign_at(Statement::Invalid(hash), 1),
+				),
+			};
+
+			assert!(report.check());
+		}
+
+		fn hex_to_hash(hex: &str) -> Hash {
+			let mut bytes = [0u8; 32];
+			for (i, byte) in bytes.iter_mut().enumerate() {
+				*byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).expect("valid hex digit");
+			}
+			Hash::from(bytes)
+		}
+
+		#[test]
+		fn keccak_256_matches_known_test_vectors() {
+			assert_eq!(
+				Hash::from(keccak_256(b"")),
+				hex_to_hash("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+			);
+			assert_eq!(
+				Hash::from(keccak_256(b"abc")),
+				hex_to_hash("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+			);
+		}
+
+		#[test]
+		fn hash_algo_keccak256_hash_of_matches_known_test_vector() {
+			// `hash_of` hashes the value's encoding: for a `Vec<u8>` that is the
+			// little-endian `u32` length prefix followed by the bytes, i.e. `03000000 616263`.
+			assert_eq!(
+				HashAlgo::Keccak256.hash_of(&b"abc".to_vec()),
+				hex_to_hash("e3b9babb640b590b338a90440465c015e8c007497ccf5f457682009a4d60ad67"),
+			);
+		}
+
+		#[test]
+		fn candidate_receipt_hash_rejects_algo_mismatch() {
+			let receipt = candidate(1, b"a");
+			let registered = ValidationCodeMeta {
+				code: ValidationCode(Vec::new()),
+				hash_algo: HashAlgo::Keccak256,
+			};
+
+			// The candidate claims `Blake2_256` but the parachain is registered for
+			// `Keccak256`, so the collator cannot pick its own convenient digest function.
+			assert!(receipt.hash(&registered).is_none());
+		}
+
+		#[test]
+		fn candidate_receipt_hash_accepts_matching_algo() {
+			let mut receipt = candidate(1, b"a");
+			receipt.hash_algo = HashAlgo::Keccak256;
+			let registered = ValidationCodeMeta {
+				code: ValidationCode(Vec::new()),
+				hash_algo: HashAlgo::Keccak256,
+			};
+
+			assert_eq!(receipt.hash(&registered), Some(HashAlgo::Keccak256.hash_of(&receipt)));
+		}
+	}
 }
\ No newline at end of file